@@ -3,8 +3,13 @@
 #![no_main]
 #![no_std]
 
-use panic_halt as _; 
+use panic_halt as _;
 use cortex_m_rt::entry;
+use embedded_graphics::{
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Rectangle},
+};
 use ra8835a::Command;
 use stm32f4xx_hal as hal;
 use crate::hal::{pac, prelude::*};
@@ -45,9 +50,19 @@ fn main() -> ! {
         display.driver.write_command(Command::CsrDirRight);
         display.driver.write_text_at("RA8835A", 220, 75);
 
-        display.draw_rectangle(50, 50, 150, 150);
-        display.draw_rectangle(100, 100, 200, 200);
-        display.draw_line(50, 50, 200, 200);
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+        Rectangle::with_corners(Point::new(50, 50), Point::new(150, 150))
+            .into_styled(style)
+            .draw(&mut display.driver)
+            .ok();
+        Rectangle::with_corners(Point::new(100, 100), Point::new(200, 200))
+            .into_styled(style)
+            .draw(&mut display.driver)
+            .ok();
+        Line::new(Point::new(50, 50), Point::new(200, 200))
+            .into_styled(style)
+            .draw(&mut display.driver)
+            .ok();
 
 
 
@@ -1,6 +1,12 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 #![deny(unsafe_code)]
 
+use embedded_graphics::{
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::Rectangle,
+    Pixel,
+};
 use embedded_hal::delay::DelayNs;
 use embedded_hal::digital::OutputPin;
 
@@ -42,6 +48,42 @@ pub enum Command {
     Mread = 0x43,
 }
 
+/// Selects which parallel bus protocol the low-level transfer functions
+/// drive the `wr`/`rd` pins with. See the field docs on `RA8835A` for how
+/// each pin is used in each mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusMode {
+    /// 8080-style bus: `wr`/`rd` are independent active-low write/read strobes.
+    Intel8080,
+    /// 6800-style bus: `wr` is a level R/W select, `rd` is an active-high enable clock.
+    Motorola6800,
+}
+
+/// Low-level parallel bus timing, in nanoseconds. The defaults are 10 ns
+/// setup, 150 ns strobe width, 30 ns read access and 30 ns hold.
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    /// Address/data setup time before the strobe or enable-clock edge.
+    pub address_setup_ns: u32,
+    /// Width of the active portion of the write strobe / enable clock.
+    pub strobe_width_ns: u32,
+    /// Access time from enable-clock asserted to valid read data.
+    pub read_access_ns: u32,
+    /// Hold time after the strobe or enable-clock edge is released.
+    pub hold_ns: u32,
+}
+
+impl Default for Timing {
+    fn default() -> Self {
+        Self {
+            address_setup_ns: 10,
+            strobe_width_ns: 150,
+            read_access_ns: 30,
+            hold_ns: 30,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Config {
     pub font_width: u8,
@@ -50,6 +92,14 @@ pub struct Config {
     pub screen_height: u16,
     pub text_layer_start: u16,
     pub graphics_layer_start: u16,
+    /// Start address of character-generator RAM, immediately after the
+    /// graphics layer.
+    pub cgram_start: u16,
+    /// Number of character codes, starting at 0, routed to CGRAM instead of
+    /// the mask ROM font. 0 disables CGRAM entirely.
+    pub cgram_glyphs: u8,
+    pub bus_mode: BusMode,
+    pub timing: Timing,
 }
 
 
@@ -64,12 +114,36 @@ impl Config {
         let text_layer_size = chars_per_line * lines;
         let text_layer_start = 0x0000;
         let graphics_layer_start = text_layer_start + text_layer_size;
+        let graphics_layer_size = (screen_width / 8) * screen_height;
+        let cgram_start = graphics_layer_start + graphics_layer_size;
         Ok(Self {
             font_width, font_height,
             screen_width, screen_height,
             text_layer_start, graphics_layer_start,
+            cgram_start, cgram_glyphs: 0,
+            bus_mode: BusMode::Intel8080,
+            timing: Timing::default(),
         })
     }
+
+    /// Select the parallel bus protocol. Defaults to `BusMode::Intel8080`.
+    pub fn with_bus_mode(mut self, bus_mode: BusMode) -> Self {
+        self.bus_mode = bus_mode;
+        self
+    }
+
+    /// Override the low-level bus timing. Defaults to `Timing::default()`.
+    pub fn with_timing(mut self, timing: Timing) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// Route `count` character codes, starting at 0, to CGRAM instead of the
+    /// mask ROM font. Defaults to 0 (CGRAM disabled).
+    pub fn with_cgram_glyphs(mut self, count: u8) -> Self {
+        self.cgram_glyphs = count;
+        self
+    }
 }
 
 
@@ -151,6 +225,12 @@ where
         for param in params {
             self.write_data(param)?;
         }
+
+        if self.config.cgram_glyphs > 0 {
+            self.write_command(Command::CgRamAdr)?;
+            self.write_data((self.config.cgram_start & 0xFF) as u8)?;
+            self.write_data((self.config.cgram_start >> 8) as u8)?;
+        }
         Ok(())
     }
 
@@ -220,16 +300,38 @@ where
         self.write_data(0x05)?;
         self.write_data((1 << 7) + self.config.font_height)?; // Block cursor.
         self.write_command(Command::Ovlay)?;
-        self.write_data(0x00)?;
+        // Bit 2 routes the low `cgram_glyphs` character codes to CGRAM.
+        self.write_data(if self.config.cgram_glyphs > 0 { 0x04 } else { 0x00 })?;
         self.write_command(Command::DisplayOn)?;
         Ok(())
     }
 
+    /// Install a user glyph at `code` in CGRAM, one byte per row, streamed
+    /// with `Mwrite`. `initialize()` must have been run with
+    /// `config.cgram_glyphs > code` for the mask-ROM font to be bypassed for
+    /// this code; it already programs the CGRAM base address via
+    /// `CgRamAdr`, so this only needs to position the cursor within CGRAM.
+    pub fn define_char(&mut self, code: u8, bitmap: &[u8]) -> Result<(), E> {
+        let slot_addr = self.config.cgram_start + code as u16 * self.config.font_height as u16;
+        self.set_cursor_address(slot_addr)?;
+        self.write_command(Command::CsrDirRight)?;
+        self.write_command(Command::Mwrite)?;
+        for &row in bitmap.iter().take(self.config.font_height as usize) {
+            self.write_data(row)?;
+        }
+        Ok(())
+    }
+
     /// Draw pixel at xy. `color` determines if pixel will be drawn or erased.
+    ///
+    /// The graphics layer packs 8 pixels per byte regardless of the text
+    /// layer's font width, so addressing here must match `fill_contiguous`
+    /// and the other graphics primitives (`fill_rect`, `copy_rect`,
+    /// `blit_mask`): `bytes_per_line = screen_width / 8`, bit `7 - (x & 7)`.
     pub fn set_pixel(&mut self, x: u16, y: u16, color: bool) -> Result<(), E> {
-        let bit_mask = 1 << 0x07 - (x % self.config.font_width as u16);
-        let bytes_per_line = self.config.screen_width / self.config.font_width as u16;
-        let byte_addr = self.config.graphics_layer_start + (y * bytes_per_line) + (x / self.config.font_width as u16);
+        let bit_mask = 1u8 << (7 - (x & 7));
+        let bytes_per_line = self.config.screen_width / 8;
+        let byte_addr = self.config.graphics_layer_start + (y * bytes_per_line) + (x >> 3);
         self.set_cursor_address(byte_addr)?;
         self.write_command(Command::Mread)?;
         let current = self.read_data().unwrap_or(0);
@@ -246,31 +348,59 @@ where
     pub fn write_command(&mut self, cmd: Command) -> Result<(), E> {
         self.a0.set_high();
         self.data.write(cmd as u8);
-        self.delay.delay_ns(10);
-        self.wr.set_low();
-        self.delay.delay_ns(150);
-        self.wr.set_high();
-        Ok(())
+        self.strobe_write()
     }
 
     pub fn write_data(&mut self, data: u8) -> Result<(), E> {
         self.a0.set_low();
         self.data.write(data);
-        self.delay.delay_ns(10);
-        self.wr.set_low();
-        self.delay.delay_ns(150);
-        self.wr.set_high();
+        self.strobe_write()
+    }
+
+    /// Drive the write strobe (8080) or the R/W level + enable clock (6800)
+    /// to latch whatever `a0`/`data` currently hold.
+    fn strobe_write(&mut self) -> Result<(), E> {
+        self.delay.delay_ns(self.config.timing.address_setup_ns);
+        match self.config.bus_mode {
+            BusMode::Intel8080 => {
+                self.wr.set_low();
+                self.delay.delay_ns(self.config.timing.strobe_width_ns);
+                self.wr.set_high();
+            }
+            BusMode::Motorola6800 => {
+                self.wr.set_low(); // R/W = write
+                self.rd.set_high(); // enable clock pulse
+                self.delay.delay_ns(self.config.timing.strobe_width_ns);
+                self.rd.set_low();
+            }
+        }
+        self.delay.delay_ns(self.config.timing.hold_ns);
         Ok(())
     }
 
     pub fn read_data(&mut self) -> Result<u8, E> {
         self.data.set_input();
         self.a0.set_high();
-        self.rd.set_low();
-        self.delay.delay_ns(30);
-        let result = self.data.read()?;
-        self.delay.delay_ns(30);
-        self.rd.set_high();
+        self.delay.delay_ns(self.config.timing.address_setup_ns);
+        let result = match self.config.bus_mode {
+            BusMode::Intel8080 => {
+                self.rd.set_low();
+                self.delay.delay_ns(self.config.timing.read_access_ns);
+                let result = self.data.read()?;
+                self.delay.delay_ns(self.config.timing.read_access_ns);
+                self.rd.set_high();
+                result
+            }
+            BusMode::Motorola6800 => {
+                self.wr.set_high(); // R/W = read
+                self.rd.set_high(); // enable clock pulse
+                self.delay.delay_ns(self.config.timing.read_access_ns);
+                let result = self.data.read()?;
+                self.rd.set_low();
+                self.delay.delay_ns(self.config.timing.hold_ns);
+                result
+            }
+        };
         self.data.set_output();
         Ok(result)
     }
@@ -281,6 +411,517 @@ where
         self.write_data((address >> 8) as u8)?;
         Ok(())
     }
+
+    /// Stream the rows of `fb` touched since the last flush to the graphics
+    /// layer in a single `Mwrite` burst. Does nothing if `fb` has no dirty
+    /// rows.
+    pub fn flush<const N: usize>(&mut self, fb: &mut Framebuffer<N>) -> Result<(), E> {
+        let Some((min_y, max_y)) = fb.dirty else {
+            return Ok(());
+        };
+        let bytes_per_line = fb.bytes_per_line as usize;
+        let start = min_y as usize * bytes_per_line;
+        let end = (max_y as usize + 1) * bytes_per_line;
+        let addr = self.config.graphics_layer_start + min_y * fb.bytes_per_line;
+        self.set_cursor_address(addr)?;
+        self.write_command(Command::CsrDirRight)?;
+        self.write_command(Command::Mwrite)?;
+        for &byte in &fb.bytes[start..end] {
+            self.write_data(byte)?;
+        }
+        fb.dirty = None;
+        Ok(())
+    }
+
+    /// Stream the byte columns covering `[x0, x1]` for rows `[y0, y1]` to the
+    /// graphics layer, regardless of `fb`'s dirty tracking. One `Mwrite`
+    /// burst is issued per scanline. Rows or columns outside `fb`'s bounds
+    /// are skipped rather than panicking, matching `Framebuffer::set_pixel`.
+    pub fn flush_region<const N: usize>(&mut self, fb: &Framebuffer<N>, x0: u16, y0: u16, x1: u16, y1: u16) -> Result<(), E> {
+        if x0 / 8 >= fb.bytes_per_line {
+            return Ok(());
+        }
+        let first_col = x0 / 8;
+        let last_col = core::cmp::min(x1 / 8, fb.bytes_per_line - 1);
+        for y in y0..=y1 {
+            let row_start = y as usize * fb.bytes_per_line as usize + first_col as usize;
+            let row_end = y as usize * fb.bytes_per_line as usize + last_col as usize + 1;
+            let Some(row) = fb.bytes.get(row_start..row_end) else {
+                continue;
+            };
+            let addr = self.config.graphics_layer_start + y * fb.bytes_per_line + first_col;
+            self.set_cursor_address(addr)?;
+            self.write_command(Command::CsrDirRight)?;
+            self.write_command(Command::Mwrite)?;
+            for &byte in row {
+                self.write_data(byte)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill `[x0, x1] x [y0, y1]` with a repeating 8-pixel `pattern`, one
+    /// `Mwrite` burst per scanline. The byte straddling each edge of the
+    /// rectangle is read-modify-written so pixels outside the rectangle but
+    /// sharing a byte with it are preserved.
+    pub fn fill_rect(&mut self, x0: u16, y0: u16, x1: u16, y1: u16, pattern: u8) -> Result<(), E> {
+        let (x0, x1) = if x0 <= x1 { (x0, x1) } else { (x1, x0) };
+        let (y0, y1) = if y0 <= y1 { (y0, y1) } else { (y1, y0) };
+        let bytes_per_line = self.config.screen_width / 8;
+        let first_col = x0 / 8;
+        let last_col = x1 / 8;
+        let first_lo = x0 % 8;
+        let last_hi = (x1 % 8) + 1;
+
+        for y in y0..=y1 {
+            let row_base = self.config.graphics_layer_start + y * bytes_per_line;
+            let first_byte = row_base + first_col;
+            let last_byte = row_base + last_col;
+
+            let need_first_read = first_lo != 0 || (first_byte == last_byte && last_hi != 8);
+            let first_existing = if need_first_read {
+                self.set_cursor_address(first_byte)?;
+                self.write_command(Command::Mread)?;
+                self.read_data().unwrap_or(0)
+            } else {
+                0
+            };
+            let last_existing = if last_byte != first_byte && last_hi != 8 {
+                self.set_cursor_address(last_byte)?;
+                self.write_command(Command::Mread)?;
+                self.read_data().unwrap_or(0)
+            } else {
+                0
+            };
+
+            self.set_cursor_address(first_byte)?;
+            self.write_command(Command::CsrDirRight)?;
+            self.write_command(Command::Mwrite)?;
+
+            if first_byte == last_byte {
+                let mask = bit_range_mask(first_lo, last_hi);
+                self.write_data((first_existing & !mask) | (pattern & mask))?;
+            } else {
+                let mask = bit_range_mask(first_lo, 8);
+                self.write_data((first_existing & !mask) | (pattern & mask))?;
+                for _ in (first_byte + 1)..last_byte {
+                    self.write_data(pattern)?;
+                }
+                let mask = bit_range_mask(0, last_hi);
+                self.write_data((last_existing & !mask) | (pattern & mask))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy a `width x height` rectangle of byte-aligned graphics memory from
+    /// `(src_x0, src_y0)` to `(dst_x0, dst_y0)` through a small line buffer,
+    /// one `Mread` burst followed by one `Mwrite` burst per `CHUNK_BYTES`-sized
+    /// span of each scanline. `src_x0`/`dst_x0` must be multiples of 8.
+    pub fn copy_rect(&mut self, src_x0: u16, src_y0: u16, dst_x0: u16, dst_y0: u16, width: u16, height: u16) -> Result<(), E> {
+        const CHUNK_BYTES: usize = 128;
+
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+
+        let bytes_per_line = self.config.screen_width / 8;
+        let src_col = src_x0 / 8;
+        let dst_col = dst_x0 / 8;
+        let row_bytes = (width + 7) / 8;
+        let mut buf = [0u8; CHUNK_BYTES];
+        let chunk_count = (row_bytes as usize + CHUNK_BYTES - 1) / CHUNK_BYTES;
+
+        // Like `memmove`, copy back-to-front -- both rows and, within each
+        // row, the chunks that stream it -- whenever the destination starts
+        // at a higher address than the source. The relative offset between a
+        // src row/chunk and its matching dst row/chunk is constant across the
+        // whole rectangle, so a single comparison here is enough to keep
+        // every read ahead of the write that could clobber it, whether the
+        // overlap is row-to-row (vertical shift) or confined to one wide row
+        // that spans more than one `CHUNK_BYTES` chunk (horizontal shift).
+        let src_key = src_y0 as u32 * bytes_per_line as u32 + src_col as u32;
+        let dst_key = dst_y0 as u32 * bytes_per_line as u32 + dst_col as u32;
+        let reverse = dst_key > src_key;
+
+        for i in 0..height {
+            let row = if reverse { height - 1 - i } else { i };
+            let src_row_base = self.config.graphics_layer_start + (src_y0 + row) * bytes_per_line + src_col;
+            let dst_row_base = self.config.graphics_layer_start + (dst_y0 + row) * bytes_per_line + dst_col;
+
+            for j in 0..chunk_count {
+                let chunk_index = if reverse { chunk_count - 1 - j } else { j };
+                let offset = (chunk_index * CHUNK_BYTES) as u16;
+                let chunk_len = core::cmp::min(CHUNK_BYTES as u16, row_bytes - offset) as usize;
+
+                self.set_cursor_address(src_row_base + offset)?;
+                self.write_command(Command::Mread)?;
+                for byte in buf[..chunk_len].iter_mut() {
+                    *byte = self.read_data()?;
+                }
+
+                self.set_cursor_address(dst_row_base + offset)?;
+                self.write_command(Command::CsrDirRight)?;
+                self.write_command(Command::Mwrite)?;
+                for &byte in &buf[..chunk_len] {
+                    self.write_data(byte)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Pan the graphics layer vertically by `lines` scanlines (positive
+    /// scrolls content up) by reprogramming the display-start address
+    /// through `Scroll` rather than moving any pixel data.
+    pub fn scroll_v(&mut self, lines: i16) -> Result<(), E> {
+        let bytes_per_line = self.config.screen_width / 8;
+        let layer_bytes = bytes_per_line as i32 * self.config.screen_height as i32;
+        let offset = (lines as i32 * bytes_per_line as i32).rem_euclid(layer_bytes) as u16;
+        let start = self.config.graphics_layer_start.wrapping_add(offset);
+
+        self.write_command(Command::Scroll)?;
+        self.write_data((self.config.text_layer_start & 0xFF) as u8)?;
+        self.write_data((self.config.text_layer_start >> 8) as u8)?;
+        self.write_data(self.config.screen_height as u8)?;
+        self.write_data((start & 0xFF) as u8)?;
+        self.write_data((start >> 8) as u8)?;
+        self.write_data(self.config.screen_height as u8)?;
+        Ok(())
+    }
+
+    /// Pan the graphics layer horizontally by `dots` (0-7) via the
+    /// controller's fine horizontal scroll register.
+    pub fn scroll_h(&mut self, dots: u8) -> Result<(), E> {
+        self.write_command(Command::HdotScr)?;
+        self.write_data(dots & 0x07)?;
+        Ok(())
+    }
+
+    /// Blit a `width x height` byte-per-pixel (truthy = lit) `src` buffer
+    /// into the graphics layer at `(x, y)`, one `Mwrite` burst per scanline.
+    ///
+    /// Each row is packed 8 source pixels at a time with a branchless bit
+    /// interleave, then shifted across the byte boundary implied by `x % 8`
+    /// and combined with the previous packed byte's carry-out. Only the
+    /// first and last destination byte of each row need a read-modify-write,
+    /// to preserve the pixels outside `[x, x + width)` that share a byte
+    /// with the blit.
+    ///
+    /// `src` should hold `width * height` samples, row-major; a short buffer
+    /// is tolerated by treating missing samples (including a truncated final
+    /// row) as unlit rather than panicking.
+    pub fn blit_mask(&mut self, x: u16, y: u16, width: u16, height: u16, src: &[u8]) -> Result<(), E> {
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        let bytes_per_line = self.config.screen_width / 8;
+        let shift = x % 8;
+        let first_col = x / 8;
+        let last_col = (x + width - 1) / 8;
+        let last_hi = ((x + width - 1) % 8) + 1;
+        let groups = ((width + 7) / 8) as usize;
+        let dest_cols = (last_col - first_col + 1) as usize;
+
+        for row in 0..height {
+            let row_start = row as usize * width as usize;
+            let row_end = row_start + width as usize;
+            let src_row = if row_start >= src.len() {
+                &[][..]
+            } else {
+                &src[row_start..row_end.min(src.len())]
+            };
+            let row_base = self.config.graphics_layer_start + (y + row) * bytes_per_line + first_col;
+
+            let need_first_read = shift != 0 || (dest_cols == 1 && last_hi != 8);
+            let first_existing = if need_first_read {
+                self.set_cursor_address(row_base)?;
+                self.write_command(Command::Mread)?;
+                self.read_data().unwrap_or(0)
+            } else {
+                0
+            };
+            let last_existing = if dest_cols > 1 && last_hi != 8 {
+                self.set_cursor_address(row_base + (dest_cols as u16 - 1))?;
+                self.write_command(Command::Mread)?;
+                self.read_data().unwrap_or(0)
+            } else {
+                0
+            };
+
+            self.set_cursor_address(row_base)?;
+            self.write_command(Command::CsrDirRight)?;
+            self.write_command(Command::Mwrite)?;
+
+            let mut prev_packed = 0u8;
+            for k in 0..dest_cols {
+                let packed = if k < groups && k * 8 < src_row.len() {
+                    let start = k * 8;
+                    let end = core::cmp::min(start + 8, src_row.len());
+                    let mut chunk = [0u8; 8];
+                    chunk[..end - start].copy_from_slice(&src_row[start..end]);
+                    pack_byte_msb_first(&chunk)
+                } else {
+                    0
+                };
+
+                let combined = if shift == 0 { packed } else { (packed >> shift) | (prev_packed << (8 - shift)) };
+                prev_packed = packed;
+
+                let is_first = k == 0;
+                let is_last = k + 1 == dest_cols;
+                let byte = match (is_first, is_last) {
+                    (true, true) => {
+                        let mask = bit_range_mask(shift, last_hi);
+                        (combined & mask) | (first_existing & !mask)
+                    }
+                    (true, false) => {
+                        let mask = bit_range_mask(shift, 8);
+                        (combined & mask) | (first_existing & !mask)
+                    }
+                    (false, true) => {
+                        let mask = bit_range_mask(0, last_hi);
+                        (combined & mask) | (last_existing & !mask)
+                    }
+                    (false, false) => combined,
+                };
+                self.write_data(byte)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A RAM-backed framebuffer for the graphics layer. `set_pixel`/`draw_line`/
+/// `draw_rectangle` only mutate `N` bytes of MCU RAM; call
+/// [`RA8835A::flush`] (one burst) or [`RA8835A::flush_region`] (one burst
+/// per scanline) to stream the result to the controller instead of one
+/// read-modify-write per pixel.
+///
+/// `N` must equal `(screen_width / 8) * screen_height` for the `Config` this
+/// framebuffer is used with.
+pub struct Framebuffer<const N: usize> {
+    bytes: [u8; N],
+    bytes_per_line: u16,
+    dirty: Option<(u16, u16)>,
+}
+
+impl<const N: usize> Framebuffer<N> {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            bytes: [0; N],
+            bytes_per_line: config.screen_width / 8,
+            dirty: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.bytes = [0; N];
+        self.dirty = None;
+    }
+
+    /// Draw pixel at xy. `color` determines if pixel will be drawn or erased.
+    pub fn set_pixel(&mut self, x: u16, y: u16, color: bool) {
+        let bit = 1u8 << (7 - (x & 7));
+        let index = y as usize * self.bytes_per_line as usize + (x >> 3) as usize;
+        let Some(byte) = self.bytes.get_mut(index) else {
+            return;
+        };
+        if color {
+            *byte |= bit;
+        } else {
+            *byte &= !bit;
+        }
+        self.dirty = Some(match self.dirty {
+            Some((min_y, max_y)) => (min_y.min(y), max_y.max(y)),
+            None => (y, y),
+        });
+    }
+
+    pub fn draw_line(&mut self, x0: u16, y0: u16, x1: u16, y1: u16, color: bool) {
+        // Bresenham's line algorithm.
+        let dx = (x1 as i16 - x0 as i16).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let dy = -(y1 as i16 - y0 as i16).abs();
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        let (mut x, mut y) = (x0 as i16, y0 as i16);
+        loop {
+            self.set_pixel(x as u16, y as u16, color);
+            if x == x1 as i16 && y == y1 as i16 { break }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    pub fn draw_rectangle(&mut self, x0: u16, y0: u16, x1: u16, y1: u16, color: bool) {
+        let (start_x, end_x) = if x0 <= x1 { (x0, x1) } else { (x1, x0) };
+        let (start_y, end_y) = if y0 <= y1 { (y0, y1) } else { (y1, y0) };
+        for x in start_x..=end_x {
+            self.set_pixel(x, start_y, color);
+            self.set_pixel(x, end_y, color);
+        }
+        for y in start_y + 1..end_y {
+            self.set_pixel(start_x, y, color);
+            self.set_pixel(end_x, y, color);
+        }
+    }
+}
+
+/// Mask selecting bits `[lo, hi)` of an MSB-first packed byte, where bit `b`
+/// lives at `1 << (7 - b)`.
+fn bit_range_mask(lo: u16, hi: u16) -> u8 {
+    let mask_lo = if lo == 0 { 0xFF } else { 0xFFu8 >> lo };
+    let mask_hi = if hi >= 8 { 0xFF } else { !(0xFFu8 >> hi) };
+    mask_lo & mask_hi
+}
+
+/// Pack 8 byte-per-pixel source samples (truthy = lit) into one MSB-first
+/// graphics-layer byte, without a per-bit conditional. `src[0]` becomes bit
+/// 7, `src[7]` becomes bit 0.
+///
+/// Spreads the 8 low bits of a 64-bit accumulator into one bit per byte lane
+/// (the Amiga bit-interleave trick), then gathers those lanes back into a
+/// single MSB-first byte with the classic movemask-style broadcast multiply.
+fn pack_byte_msb_first(src: &[u8; 8]) -> u8 {
+    let mut r: u64 = 0;
+    for (i, &sample) in src.iter().enumerate() {
+        r |= ((sample != 0) as u64) << i;
+    }
+    r = (r | (r << 28)) & 0x0000_000f_0000_000f;
+    r = (r | (r << 14)) & 0x0003_0003_0003_0003;
+    r = (r | (r << 7)) & 0x0101_0101_0101_0101;
+    (r.wrapping_mul(0x8040_2010_0804_0201) >> 56) as u8
+}
+
+impl<DATA, A0, WR, RD, CS, RES, DELAY, E> OriginDimensions for RA8835A<DATA, A0, WR, RD, CS, RES, DELAY>
+where
+    DATA: ParallelBus<Error = E>,
+    A0: OutputPin,
+    WR: OutputPin,
+    RD: OutputPin,
+    CS: OutputPin,
+    RES: OutputPin,
+    DELAY: DelayNs,
+{
+    fn size(&self) -> Size {
+        Size::new(self.config.screen_width as u32, self.config.screen_height as u32)
+    }
+}
+
+impl<DATA, A0, WR, RD, CS, RES, DELAY, E> DrawTarget for RA8835A<DATA, A0, WR, RD, CS, RES, DELAY>
+where
+    DATA: ParallelBus<Error = E>,
+    A0: OutputPin,
+    WR: OutputPin,
+    RD: OutputPin,
+    CS: OutputPin,
+    RES: OutputPin,
+    DELAY: DelayNs,
+{
+    type Color = BinaryColor;
+    type Error = E;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_box();
+        for Pixel(point, color) in pixels.into_iter() {
+            if bb.contains(point) {
+                self.set_pixel(point.x as u16, point.y as u16, color.is_on())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Stream a rectangular run of colors as packed graphics-layer bytes.
+    ///
+    /// The common case (area fully on-screen) issues a single `Csrw` +
+    /// `Mwrite` per scanline and streams whole bytes back-to-back, only
+    /// falling back to a read-modify-write for the partial byte at each end
+    /// of the run. Areas that are not fully on-screen fall back to
+    /// `draw_iter` since they need per-pixel clipping anyway.
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let bb = self.bounding_box();
+        let bottom_right = match area.bottom_right() {
+            Some(br) => br,
+            None => return Ok(()),
+        };
+        if area.top_left.x < 0 || area.top_left.y < 0 || !bb.contains(area.top_left) || !bb.contains(bottom_right) {
+            return self.draw_iter(area.points().zip(colors).map(|(point, color)| Pixel(point, color)));
+        }
+
+        let mut colors = colors.into_iter();
+        let x0 = area.top_left.x as u16;
+        let y0 = area.top_left.y as u16;
+        let width = area.size.width as u16;
+        let height = area.size.height as u16;
+        let bytes_per_line = self.config.screen_width / 8;
+
+        for row in 0..height {
+            let y = y0 + row;
+            let row_base = self.config.graphics_layer_start + y * bytes_per_line;
+            let first_byte = row_base + x0 / 8;
+            let last_byte = row_base + (x0 + width - 1) / 8;
+            let first_lo = x0 % 8;
+            let last_hi = ((x0 + width - 1) % 8) + 1;
+
+            let need_first_read = first_lo != 0 || (first_byte == last_byte && last_hi != 8);
+            let first_existing = if need_first_read {
+                self.set_cursor_address(first_byte)?;
+                self.write_command(Command::Mread)?;
+                self.read_data().unwrap_or(0)
+            } else {
+                0
+            };
+            let last_existing = if last_byte != first_byte && last_hi != 8 {
+                self.set_cursor_address(last_byte)?;
+                self.write_command(Command::Mread)?;
+                self.read_data().unwrap_or(0)
+            } else {
+                0
+            };
+
+            self.set_cursor_address(first_byte)?;
+            self.write_command(Command::CsrDirRight)?;
+            self.write_command(Command::Mwrite)?;
+
+            if first_byte == last_byte {
+                let byte = pack_byte(first_existing, first_lo, last_hi, &mut colors);
+                self.write_data(byte)?;
+            } else {
+                self.write_data(pack_byte(first_existing, first_lo, 8, &mut colors))?;
+                for _ in (first_byte + 1)..last_byte {
+                    self.write_data(pack_byte(0, 0, 8, &mut colors))?;
+                }
+                self.write_data(pack_byte(last_existing, 0, last_hi, &mut colors))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Merge up to 8 colors from `colors` into `existing`, overwriting bits
+/// `[lo, hi)` (MSB-first) and leaving the rest untouched.
+fn pack_byte<I: Iterator<Item = BinaryColor>>(existing: u8, lo: u16, hi: u16, colors: &mut I) -> u8 {
+    let mut byte = existing & !bit_range_mask(lo, hi);
+    for bit in lo..hi {
+        if colors.next().unwrap_or(BinaryColor::Off).is_on() {
+            byte |= 1 << (7 - bit);
+        }
+    }
+    byte
 }
 
 pub trait ParallelBus {
@@ -291,3 +932,644 @@ pub trait ParallelBus {
     fn set_input(&mut self) -> ();
     fn set_output(&mut self) -> ();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::{Cell, RefCell};
+    use core::convert::Infallible;
+    use embedded_hal::digital::ErrorType;
+
+    #[test]
+    fn bit_range_mask_selects_expected_bits() {
+        assert_eq!(bit_range_mask(0, 8), 0xFF);
+        assert_eq!(bit_range_mask(0, 4), 0b1111_0000);
+        assert_eq!(bit_range_mask(4, 8), 0b0000_1111);
+        assert_eq!(bit_range_mask(2, 6), 0b0011_1100);
+        assert_eq!(bit_range_mask(3, 3), 0);
+    }
+
+    #[test]
+    fn pack_byte_msb_first_matches_bitwise_reference() {
+        assert_eq!(pack_byte_msb_first(&[1, 0, 1, 1, 0, 0, 0, 1]), 0b1011_0001);
+        assert_eq!(pack_byte_msb_first(&[0xFF; 8]), 0xFF);
+        assert_eq!(pack_byte_msb_first(&[0; 8]), 0x00);
+    }
+
+    // Minimal emulation of the RA8835A's memory interface, enough to drive
+    // `RA8835A` through the real command protocol (Csrw/Mread/Mwrite) and
+    // inspect the resulting memory contents, without any hardware.
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum BusOp {
+        Idle,
+        Read,
+        Write,
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum Pending {
+        None,
+        CursorLo,
+        CursorHi(u8),
+    }
+
+    struct MockController {
+        a0_high: Cell<bool>,
+        mem: RefCell<[u8; 65536]>,
+        cursor: Cell<u16>,
+        op: Cell<BusOp>,
+        pending: Cell<Pending>,
+        /// Every `(a0, value)` pair handed to `MockBus::write`, in order.
+        /// `MockBus` itself only understands the Csrw/Mread/Mwrite opcodes,
+        /// so this is what lets a test assert the parameter bytes of any
+        /// other command (e.g. `Scroll`, `HdotScr`, `CgRamAdr`, `Ovlay`).
+        trace: RefCell<Vec<(bool, u8)>>,
+    }
+
+    impl MockController {
+        fn new() -> Self {
+            Self {
+                a0_high: Cell::new(false),
+                mem: RefCell::new([0u8; 65536]),
+                cursor: Cell::new(0),
+                op: Cell::new(BusOp::Idle),
+                pending: Cell::new(Pending::None),
+                trace: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    struct MockA0<'a>(&'a MockController);
+    impl<'a> ErrorType for MockA0<'a> {
+        type Error = Infallible;
+    }
+    impl<'a> OutputPin for MockA0<'a> {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            self.0.a0_high.set(false);
+            Ok(())
+        }
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            self.0.a0_high.set(true);
+            Ok(())
+        }
+    }
+
+    struct MockPin;
+    impl ErrorType for MockPin {
+        type Error = Infallible;
+    }
+    impl OutputPin for MockPin {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    /// Like `MockPin`, but records every transition (tagged by `name`) into a
+    /// shared trace, so a test can assert the exact `wr`/`rd` sequence a bus
+    /// mode drives instead of only observing `MockBus`'s memory side effects.
+    struct TracingPin<'a> {
+        name: &'static str,
+        trace: &'a RefCell<Vec<(&'static str, bool)>>,
+    }
+    impl<'a> ErrorType for TracingPin<'a> {
+        type Error = Infallible;
+    }
+    impl<'a> OutputPin for TracingPin<'a> {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            self.trace.borrow_mut().push((self.name, false));
+            Ok(())
+        }
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            self.trace.borrow_mut().push((self.name, true));
+            Ok(())
+        }
+    }
+
+    struct MockDelay;
+    impl DelayNs for MockDelay {
+        fn delay_ns(&mut self, _ns: u32) {}
+    }
+
+    struct MockBus<'a>(&'a MockController);
+    impl<'a> ParallelBus for MockBus<'a> {
+        type Error = Infallible;
+
+        fn write(&mut self, value: u8) {
+            let c = self.0;
+            c.trace.borrow_mut().push((c.a0_high.get(), value));
+            if c.a0_high.get() {
+                match value {
+                    0x46 => c.pending.set(Pending::CursorLo), // Csrw
+                    0x43 => c.op.set(BusOp::Read),             // Mread
+                    0x42 => c.op.set(BusOp::Write),            // Mwrite
+                    _ => {}
+                }
+                return;
+            }
+            match c.pending.get() {
+                Pending::CursorLo => c.pending.set(Pending::CursorHi(value)),
+                Pending::CursorHi(lo) => {
+                    c.cursor.set(lo as u16 | ((value as u16) << 8));
+                    c.pending.set(Pending::None);
+                }
+                Pending::None => {
+                    if c.op.get() == BusOp::Write {
+                        let idx = c.cursor.get() as usize;
+                        c.mem.borrow_mut()[idx] = value;
+                        c.cursor.set(c.cursor.get().wrapping_add(1));
+                    }
+                }
+            }
+        }
+
+        fn read(&mut self) -> Result<u8, Infallible> {
+            let idx = self.0.cursor.get() as usize;
+            let byte = self.0.mem.borrow()[idx];
+            self.0.cursor.set(self.0.cursor.get().wrapping_add(1));
+            Ok(byte)
+        }
+
+        fn set_input(&mut self) {}
+        fn set_output(&mut self) {}
+    }
+
+    #[test]
+    fn copy_rect_handles_wide_row_overlap_without_clobbering() {
+        let controller = MockController::new();
+        // font_width=8, screen_width=1912 is the widest multiple-of-8 width
+        // this crate allows for an 8px font (see `Config::new`'s `cr > 239`
+        // check), giving a
+        // 239-byte-per-line row -- wide enough that a rect copy spans more
+        // than one `CHUNK_BYTES` (128-byte) chunk per scanline.
+        let config = Config::new(8, 8, 1912, 8).unwrap();
+        let graphics_start = config.graphics_layer_start;
+
+        let mut driver = RA8835A::new(
+            MockBus(&controller),
+            MockA0(&controller),
+            MockPin,
+            MockPin,
+            MockPin,
+            MockPin,
+            MockDelay,
+            config,
+        )
+        .unwrap();
+
+        // Seed the source row (238 bytes = 1904px) with a distinct pattern,
+        // then copy it 1 byte (8px) to the right on the same row -- a pure
+        // intra-row overlap that spans two chunks.
+        let expected: [u8; 238] = core::array::from_fn(|i| (i as u8).wrapping_mul(7).wrapping_add(3));
+        {
+            let mut mem = controller.mem.borrow_mut();
+            for (i, &byte) in expected.iter().enumerate() {
+                mem[graphics_start as usize + i] = byte;
+            }
+        }
+
+        driver.copy_rect(0, 0, 8, 0, 1904, 1).unwrap();
+
+        let mem = controller.mem.borrow();
+        let dst_base = graphics_start as usize + 1;
+        for (i, &expected_byte) in expected.iter().enumerate() {
+            assert_eq!(mem[dst_base + i], expected_byte, "byte {i} corrupted by intra-row overlap");
+        }
+    }
+
+    #[test]
+    fn blit_mask_packs_pixels_and_preserves_edges_across_shifts_and_widths() {
+        let config = Config::new(8, 8, 64, 8).unwrap();
+        let graphics_start = config.graphics_layer_start as usize;
+        let bytes_per_line = (config.screen_width / 8) as usize;
+        let height = 2u16;
+
+        // Sweep every byte-alignment (`x % 8`) against widths that exercise a
+        // single partial column, an exact byte, and multiple full + partial
+        // columns, mirroring `copy_rect`'s overlap coverage above.
+        for x in 0..8u16 {
+            for &width in &[1u16, 3, 8, 9, 13] {
+                let controller = MockController::new();
+                let mut driver = RA8835A::new(
+                    MockBus(&controller),
+                    MockA0(&controller),
+                    MockPin,
+                    MockPin,
+                    MockPin,
+                    MockPin,
+                    MockDelay,
+                    config,
+                )
+                .unwrap();
+                {
+                    // Sentinel pattern so bits outside the blit -- including
+                    // the untouched half of each edge byte -- must survive.
+                    // Seeded after `new()` since it clears the graphics layer.
+                    let mut mem = controller.mem.borrow_mut();
+                    for i in 0..bytes_per_line * height as usize {
+                        mem[graphics_start + i] = 0xAA;
+                    }
+                }
+
+                let src: Vec<u8> = (0..(width as usize * height as usize))
+                    .map(|i| (i % 3 == 0) as u8)
+                    .collect();
+
+                driver.blit_mask(x, 0, width, height, &src).unwrap();
+
+                let mem = controller.mem.borrow();
+                for row in 0..height as usize {
+                    for bit_index in 0..bytes_per_line * 8 {
+                        let byte_index = graphics_start + row * bytes_per_line + bit_index / 8;
+                        let bit_mask = 1u8 << (7 - (bit_index % 8));
+                        let lit = mem[byte_index] & bit_mask != 0;
+                        let in_blit = bit_index >= x as usize && bit_index < x as usize + width as usize;
+                        let expected_lit = if in_blit {
+                            src[row * width as usize + (bit_index - x as usize)] != 0
+                        } else {
+                            0xAAu8 & bit_mask != 0
+                        };
+                        assert_eq!(
+                            lit, expected_lit,
+                            "x={x} width={width} row={row} bit={bit_index}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fill_contiguous_packs_onscreen_rect_and_falls_back_to_draw_iter_offscreen() {
+        let config = Config::new(8, 8, 64, 8).unwrap();
+        let graphics_start = config.graphics_layer_start as usize;
+        let bytes_per_line = (config.screen_width / 8) as usize;
+
+        // On-screen: spans an unaligned left edge and an unaligned right
+        // edge, so both the packed-run arm and the read-modify-write arms of
+        // `fill_contiguous` run, mirroring `blit_mask`'s alignment coverage.
+        {
+            let controller = MockController::new();
+            let mut driver = RA8835A::new(
+                MockBus(&controller),
+                MockA0(&controller),
+                MockPin,
+                MockPin,
+                MockPin,
+                MockPin,
+                MockDelay,
+                config,
+            )
+            .unwrap();
+            {
+                let mut mem = controller.mem.borrow_mut();
+                for i in 0..bytes_per_line * 2 {
+                    mem[graphics_start + i] = 0xAA;
+                }
+            }
+
+            let area = Rectangle::new(Point::new(3, 0), Size::new(10, 2));
+            driver.fill_solid(&area, BinaryColor::On).unwrap();
+
+            let mem = controller.mem.borrow();
+            for row in 0..2usize {
+                for bit_index in 0..bytes_per_line * 8 {
+                    let byte_index = graphics_start + row * bytes_per_line + bit_index / 8;
+                    let bit_mask = 1u8 << (7 - (bit_index % 8));
+                    let lit = mem[byte_index] & bit_mask != 0;
+                    let in_area = bit_index >= 3 && bit_index < 13;
+                    let expected = if in_area { true } else { 0xAAu8 & bit_mask != 0 };
+                    assert_eq!(lit, expected, "row={row} bit={bit_index}");
+                }
+            }
+        }
+
+        // Off-screen: the rectangle extends past the right edge of the
+        // display, so `fill_contiguous` must clip through `draw_iter`
+        // instead of writing out-of-bounds columns.
+        {
+            let controller = MockController::new();
+            let mut driver = RA8835A::new(
+                MockBus(&controller),
+                MockA0(&controller),
+                MockPin,
+                MockPin,
+                MockPin,
+                MockPin,
+                MockDelay,
+                config,
+            )
+            .unwrap();
+
+            let area = Rectangle::new(Point::new(60, 0), Size::new(10, 1));
+            driver.fill_solid(&area, BinaryColor::On).unwrap();
+
+            let mem = controller.mem.borrow();
+            for bit_index in 0..bytes_per_line * 8 {
+                let byte_index = graphics_start + bit_index / 8;
+                let bit_mask = 1u8 << (7 - (bit_index % 8));
+                let lit = mem[byte_index] & bit_mask != 0;
+                let expected = (60..64).contains(&bit_index);
+                assert_eq!(lit, expected, "bit={bit_index}");
+            }
+        }
+    }
+
+    #[test]
+    fn framebuffer_set_pixel_draw_line_and_draw_rectangle_track_dirty_rows_for_flush() {
+        let config = Config::new(8, 8, 64, 8).unwrap();
+        let graphics_start = config.graphics_layer_start as usize;
+        let bytes_per_line = (config.screen_width / 8) as usize;
+        let controller = MockController::new();
+        let mut driver = RA8835A::new(
+            MockBus(&controller),
+            MockA0(&controller),
+            MockPin,
+            MockPin,
+            MockPin,
+            MockPin,
+            MockDelay,
+            config,
+        )
+        .unwrap();
+
+        let mut fb: Framebuffer<64> = Framebuffer::new(&config);
+        driver.flush(&mut fb).unwrap(); // clean framebuffer: no-op
+
+        fb.draw_rectangle(0, 0, 7, 1, true); // fills byte 0 of rows 0 and 1
+        fb.draw_line(0, 3, 7, 3, true); // fills byte 0 of row 3
+        assert_eq!(fb.dirty, Some((0, 3)));
+
+        driver.flush(&mut fb).unwrap();
+        assert_eq!(fb.dirty, None, "flush must clear dirty tracking");
+
+        let mem = controller.mem.borrow();
+        for row in [0usize, 1, 3] {
+            assert_eq!(mem[graphics_start + row * bytes_per_line], 0xFF, "row {row} byte 0");
+            for col in 1..bytes_per_line {
+                assert_eq!(mem[graphics_start + row * bytes_per_line + col], 0, "row {row} col {col}");
+            }
+        }
+        // Row 2 sits inside the dirty span (0..=3) but was never itself
+        // touched, so flush() streams it as all-zero rather than skipping it.
+        for col in 0..bytes_per_line {
+            assert_eq!(mem[graphics_start + 2 * bytes_per_line + col], 0, "untouched row 2 col {col}");
+        }
+    }
+
+    #[test]
+    fn flush_region_streams_requested_columns_only_and_skips_out_of_bounds_rows() {
+        let config = Config::new(8, 8, 64, 8).unwrap();
+        let graphics_start = config.graphics_layer_start as usize;
+        let bytes_per_line = (config.screen_width / 8) as usize;
+        let controller = MockController::new();
+        let mut driver = RA8835A::new(
+            MockBus(&controller),
+            MockA0(&controller),
+            MockPin,
+            MockPin,
+            MockPin,
+            MockPin,
+            MockDelay,
+            config,
+        )
+        .unwrap();
+
+        let mut fb: Framebuffer<64> = Framebuffer::new(&config);
+        fb.set_pixel(0, 1, true); // byte col 0, row 1
+        fb.set_pixel(40, 1, true); // byte col 5, row 1
+
+        // Flush only column 0 of row 1; column 5 is dirty but outside the
+        // requested x range and must be left alone.
+        driver.flush_region(&fb, 0, 1, 7, 1).unwrap();
+
+        {
+            let mem = controller.mem.borrow();
+            assert_eq!(mem[graphics_start + bytes_per_line], 0x80);
+            assert_eq!(mem[graphics_start + bytes_per_line + 5], 0, "column outside requested range was streamed");
+        }
+
+        // Rows beyond the framebuffer's bounds are skipped rather than
+        // panicking.
+        driver.flush_region(&fb, 0, 6, 7, 20).unwrap();
+    }
+
+    #[test]
+    fn define_char_installs_glyph_and_cgram_glyphs_wires_cgramadr_and_ovlay() {
+        let config = Config::new(8, 8, 64, 8).unwrap().with_cgram_glyphs(4);
+        let cgram_start = config.cgram_start;
+        let controller = MockController::new();
+        let mut driver = RA8835A::new(
+            MockBus(&controller),
+            MockA0(&controller),
+            MockPin,
+            MockPin,
+            MockPin,
+            MockPin,
+            MockDelay,
+            config,
+        )
+        .unwrap();
+
+        {
+            // `initialize()` must program the CGRAM base address via
+            // `CgRamAdr` whenever `cgram_glyphs > 0`, and `enable_display()`
+            // must set the Ovlay bit that routes those codes to CGRAM.
+            let trace = controller.trace.borrow();
+            let cgramadr_pos = trace
+                .iter()
+                .position(|&(a0, v)| a0 && v == Command::CgRamAdr as u8)
+                .expect("initialize() must program CgRamAdr when cgram_glyphs > 0");
+            assert_eq!(trace[cgramadr_pos + 1], (false, (cgram_start & 0xFF) as u8));
+            assert_eq!(trace[cgramadr_pos + 2], (false, (cgram_start >> 8) as u8));
+
+            let ovlay_pos = trace
+                .iter()
+                .position(|&(a0, v)| a0 && v == Command::Ovlay as u8)
+                .expect("enable_display() must program Ovlay");
+            assert_eq!(
+                trace[ovlay_pos + 1],
+                (false, 0x04),
+                "Ovlay must route the low cgram_glyphs codes to CGRAM"
+            );
+        }
+
+        let glyph: [u8; 8] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80];
+        driver.define_char(2, &glyph).unwrap();
+
+        let mem = controller.mem.borrow();
+        let slot = cgram_start as usize + 2 * config.font_height as usize;
+        for (row, &expected) in glyph.iter().enumerate() {
+            assert_eq!(mem[slot + row], expected, "cgram row {row}");
+        }
+    }
+
+    #[test]
+    fn cgram_glyphs_disabled_by_default_skips_cgramadr_and_clears_ovlay_bit() {
+        let config = Config::new(8, 8, 64, 8).unwrap();
+        let controller = MockController::new();
+        let _driver = RA8835A::new(
+            MockBus(&controller),
+            MockA0(&controller),
+            MockPin,
+            MockPin,
+            MockPin,
+            MockPin,
+            MockDelay,
+            config,
+        )
+        .unwrap();
+
+        let trace = controller.trace.borrow();
+        assert!(
+            !trace.iter().any(|&(a0, v)| a0 && v == Command::CgRamAdr as u8),
+            "CgRamAdr must not be programmed when cgram_glyphs == 0"
+        );
+        let ovlay_pos = trace
+            .iter()
+            .position(|&(a0, v)| a0 && v == Command::Ovlay as u8)
+            .expect("enable_display() must program Ovlay");
+        assert_eq!(trace[ovlay_pos + 1], (false, 0x00));
+    }
+
+    #[test]
+    fn fill_rect_preserves_edges_across_alignments_and_widths() {
+        let config = Config::new(8, 8, 64, 8).unwrap();
+        let graphics_start = config.graphics_layer_start as usize;
+        let bytes_per_line = (config.screen_width / 8) as usize;
+        let height = 2u16;
+        let pattern = 0b1100_1100u8;
+
+        // Sweep every byte-alignment (`x0 % 8`) against widths that exercise
+        // a single partial column, an exact byte, and multiple full +
+        // partial columns, mirroring `blit_mask`'s alignment coverage.
+        for x0 in 0..8u16 {
+            for &width in &[1u16, 3, 8, 9, 13] {
+                let x1 = x0 + width - 1;
+                let controller = MockController::new();
+                let mut driver = RA8835A::new(
+                    MockBus(&controller),
+                    MockA0(&controller),
+                    MockPin,
+                    MockPin,
+                    MockPin,
+                    MockPin,
+                    MockDelay,
+                    config,
+                )
+                .unwrap();
+                {
+                    let mut mem = controller.mem.borrow_mut();
+                    for i in 0..bytes_per_line * height as usize {
+                        mem[graphics_start + i] = 0xAA;
+                    }
+                }
+
+                driver.fill_rect(x0, 0, x1, height - 1, pattern).unwrap();
+
+                let mem = controller.mem.borrow();
+                for row in 0..height as usize {
+                    for bit_index in 0..bytes_per_line * 8 {
+                        let byte_index = graphics_start + row * bytes_per_line + bit_index / 8;
+                        let bit_mask = 1u8 << (7 - (bit_index % 8));
+                        let lit = mem[byte_index] & bit_mask != 0;
+                        let in_rect = bit_index >= x0 as usize && bit_index <= x1 as usize;
+                        let expected = if in_rect {
+                            pattern & bit_mask != 0
+                        } else {
+                            0xAAu8 & bit_mask != 0
+                        };
+                        assert_eq!(
+                            lit, expected,
+                            "x0={x0} width={width} row={row} bit={bit_index}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scroll_v_and_scroll_h_emit_expected_parameter_bytes() {
+        let config = Config::new(8, 8, 64, 8).unwrap();
+        let bytes_per_line = (config.screen_width / 8) as u16;
+        let controller = MockController::new();
+        let mut driver = RA8835A::new(
+            MockBus(&controller),
+            MockA0(&controller),
+            MockPin,
+            MockPin,
+            MockPin,
+            MockPin,
+            MockDelay,
+            config,
+        )
+        .unwrap();
+        controller.trace.borrow_mut().clear();
+
+        driver.scroll_v(3).unwrap();
+        let start = config.graphics_layer_start.wrapping_add(3 * bytes_per_line);
+        assert_eq!(
+            &controller.trace.borrow()[..],
+            &[
+                (true, Command::Scroll as u8),
+                (false, (config.text_layer_start & 0xFF) as u8),
+                (false, (config.text_layer_start >> 8) as u8),
+                (false, config.screen_height as u8),
+                (false, (start & 0xFF) as u8),
+                (false, (start >> 8) as u8),
+                (false, config.screen_height as u8),
+            ],
+        );
+        controller.trace.borrow_mut().clear();
+
+        driver.scroll_h(11).unwrap(); // masked to 0x07 -> 3
+        assert_eq!(
+            &controller.trace.borrow()[..],
+            &[(true, Command::HdotScr as u8), (false, 3u8)],
+        );
+    }
+
+    #[test]
+    fn motorola6800_bus_drives_wr_level_and_rd_enable_clock_in_expected_order() {
+        let controller = MockController::new();
+        let trace = RefCell::new(Vec::new());
+        let config = Config::new(8, 8, 64, 8)
+            .unwrap()
+            .with_bus_mode(BusMode::Motorola6800);
+        let mut driver = RA8835A::new(
+            MockBus(&controller),
+            MockA0(&controller),
+            TracingPin { name: "wr", trace: &trace },
+            TracingPin { name: "rd", trace: &trace },
+            MockPin,
+            MockPin,
+            MockDelay,
+            config,
+        )
+        .unwrap();
+        trace.borrow_mut().clear();
+
+        // A write (e.g. the command byte of `write_command`) must hold
+        // wr=low (R/W=write) while pulsing rd (the enable clock) high then
+        // low, per `strobe_write`'s `Motorola6800` arm.
+        driver.write_command(Command::Csrr).unwrap();
+        assert_eq!(
+            &trace.borrow()[..],
+            &[("wr", false), ("rd", true), ("rd", false)],
+        );
+        trace.borrow_mut().clear();
+
+        // A read must set wr=high (R/W=read) before pulsing rd, and must
+        // keep rd high across the access-time delay while `data.read()` is
+        // called, per `read_data`'s `Motorola6800` arm.
+        let _ = driver.read_data().unwrap();
+        assert_eq!(
+            &trace.borrow()[..],
+            &[("wr", true), ("rd", true), ("rd", false)],
+        );
+    }
+}